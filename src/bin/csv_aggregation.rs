@@ -1,23 +1,163 @@
+use std::collections::HashMap;
 use std::error::Error;
 use std::fmt;
 use std::process;
 
-use csv::{Reader, StringRecord, Writer};
+use csv::{ReaderBuilder, StringRecord, WriterBuilder};
 
-/// A simple error handler structure
+/// Configuration for how a `DataSet` is read from and written to CSV,
+/// mirroring the knobs `csv::ReaderBuilder`/`csv::WriterBuilder` expose.
+#[derive(Debug, Clone)]
+struct DataSetOptions {
+    /// Field delimiter, e.g. `b','`, `b';'`, or `b'\t'`
+    delimiter: u8,
+
+    /// Whether the first row should be treated as a header row
+    has_headers: bool,
+
+    /// Whether records are allowed to have a differing number of fields
+    flexible: bool,
+
+    /// Whitespace trimming applied to fields and/or headers
+    trim: csv::Trim,
+
+    /// Record terminator. `None` leaves the reader/writer at their own
+    /// defaults (`CRLF` for reading, `\n` for writing) instead of forcing a
+    /// single terminator onto both, since those defaults differ on purpose.
+    terminator: Option<csv::Terminator>,
+}
+
+impl Default for DataSetOptions {
+    fn default() -> Self {
+        DataSetOptions {
+            delimiter: b',',
+            has_headers: true,
+            flexible: false,
+            trim: csv::Trim::None,
+            terminator: None,
+        }
+    }
+}
+
+impl DataSetOptions {
+    fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    fn has_headers(mut self, has_headers: bool) -> Self {
+        self.has_headers = has_headers;
+        self
+    }
+
+    fn flexible(mut self, flexible: bool) -> Self {
+        self.flexible = flexible;
+        self
+    }
+
+    fn trim(mut self, trim: csv::Trim) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    fn terminator(mut self, terminator: csv::Terminator) -> Self {
+        self.terminator = Some(terminator);
+        self
+    }
+
+    fn reader_builder(&self) -> ReaderBuilder {
+        let mut builder = ReaderBuilder::new();
+        builder
+            .delimiter(self.delimiter)
+            .has_headers(self.has_headers)
+            .flexible(self.flexible)
+            .trim(self.trim);
+
+        if let Some(terminator) = self.terminator {
+            builder.terminator(terminator);
+        }
+
+        builder
+    }
+
+    fn writer_builder(&self) -> WriterBuilder {
+        let mut builder = WriterBuilder::new();
+        builder.delimiter(self.delimiter).flexible(self.flexible);
+
+        if let Some(terminator) = self.terminator {
+            builder.terminator(terminator);
+        }
+
+        builder
+    }
+}
+
+/// Errors that can occur while building or querying a `DataSet`
 #[derive(Debug)]
-struct IndexError(String);
+enum DataSetError {
+    /// A requested column name does not exist in the header row
+    ColumnNotFound {
+        name: String,
+        available: Vec<String>,
+    },
+
+    /// An index was used that falls outside the header row
+    IndexOutOfBounds { index: usize, len: usize },
+
+    /// A cell could not be parsed as the expected type. Only ever
+    /// constructed by the `ndarray` conversions, which parse strictly
+    /// instead of skipping invalid cells like `Analyze::group_by` does.
+    #[cfg(feature = "ndarray")]
+    ParseField {
+        row: u64,
+        column: String,
+        value: String,
+        expected: &'static str,
+    },
+
+    /// A set of headers did not have one entry per array column. Only ever
+    /// constructed by `DataSet::from_array2`.
+    #[cfg(feature = "ndarray")]
+    ShapeMismatch { headers: usize, columns: usize },
+}
 
-impl fmt::Display for IndexError {
+impl fmt::Display for DataSetError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Index Error: {}", self.0)
+        match self {
+            DataSetError::ColumnNotFound { name, available } => write!(
+                f,
+                "column '{}' does not exist. Available columns: {}",
+                name,
+                available.join(", ")
+            ),
+            DataSetError::IndexOutOfBounds { index, len } => {
+                write!(f, "index '{}' out of bounds for {} columns", index, len)
+            }
+            #[cfg(feature = "ndarray")]
+            DataSetError::ParseField {
+                row,
+                column,
+                value,
+                expected,
+            } => write!(
+                f,
+                "row {}, column '{}': could not parse \"{}\" as {}",
+                row, column, value, expected
+            ),
+            #[cfg(feature = "ndarray")]
+            DataSetError::ShapeMismatch { headers, columns } => write!(
+                f,
+                "{} header(s) given for an array with {} column(s)",
+                headers, columns
+            ),
+        }
     }
 }
 
-impl Error for IndexError {}
+impl Error for DataSetError {}
 
 /// Internal data set to make aggregation simpler
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct DataSet {
     /// Header row of CSV file
     headers: StringRecord,
@@ -44,10 +184,10 @@ impl DataSet {
     fn key_index(&self, key: &str) -> Result<usize, Box<dyn Error>> {
         match self.headers.iter().position(|column| column == key) {
             Some(index) => Ok(index),
-            None => Err(Box::new(IndexError(format!(
-                "Column '{}' does not exist.",
-                key
-            )))),
+            None => Err(Box::new(DataSetError::ColumnNotFound {
+                name: key.to_string(),
+                available: self.headers.iter().map(String::from).collect(),
+            })),
         }
     }
 
@@ -58,10 +198,10 @@ impl DataSet {
     /// An error occurs if the index is out of bounds
     fn sort_by_index(&mut self, index: usize) -> Result<(), Box<dyn Error>> {
         if index >= self.headers.len() {
-            Err(Box::new(IndexError(format!(
-                "Index '{}' out of bounds",
-                index
-            ))))
+            Err(Box::new(DataSetError::IndexOutOfBounds {
+                index,
+                len: self.headers.len(),
+            }))
         } else {
             self.records.sort_by(|a, b| a[index].cmp(&b[index]));
             Ok(())
@@ -69,19 +209,67 @@ impl DataSet {
     }
 }
 
+/// The kind of join to perform between two data sets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JoinKind {
+    /// Keep only rows whose key matches on both sides
+    Inner,
+
+    /// Keep every left row, padding unmatched right fields with empty cells
+    Left,
+
+    /// Keep every right row, padding unmatched left fields with empty cells
+    Right,
+
+    /// Keep every row from both sides, padding whichever side has no match
+    Full,
+
+    /// Emit every left x right combination, ignoring the key entirely
+    Cross,
+
+    /// Like `Inner`, but built from a hash map over the right side instead
+    /// of sorting, preserving the left side's original row order
+    Hash,
+}
+
 /// This trait defines aggregation methods for the internal data set
 trait Aggregate {
     fn inner_join(&mut self, right: &mut Self, key: &str) -> Result<DataSet, Box<dyn Error>>;
+
+    fn left_join(&mut self, right: &mut Self, key: &str) -> Result<DataSet, Box<dyn Error>>;
+
+    fn right_join(&mut self, right: &mut Self, key: &str) -> Result<DataSet, Box<dyn Error>>;
+
+    fn full_outer_join(&mut self, right: &mut Self, key: &str) -> Result<DataSet, Box<dyn Error>>;
+
+    fn cross_join(&self, right: &Self) -> Result<DataSet, Box<dyn Error>>;
+
+    fn hash_join(&self, right: &Self, key: &str) -> Result<DataSet, Box<dyn Error>>;
+
+    /// Performs the given kind of join, dispatching to the method above
+    fn join(
+        &mut self,
+        right: &mut Self,
+        key: &str,
+        kind: JoinKind,
+    ) -> Result<DataSet, Box<dyn Error>>;
 }
 
-impl Aggregate for DataSet {
-    /// Performs an inner join on two data sets, where `self` is the left table.
+impl DataSet {
+    /// Merges two data sets with a sort-merge join, padding unmatched rows
+    /// according to `kind`.
     ///
     /// # Arguments
     ///
     /// * `right` -> The right data set for the join
     /// * `key` -> The column name to join on
-    fn inner_join(&mut self, right: &mut Self, key: &str) -> Result<DataSet, Box<dyn Error>> {
+    /// * `kind` -> Which unmatched rows (if any) to keep
+    fn merge_join(
+        &mut self,
+        right: &mut Self,
+        key: &str,
+        kind: JoinKind,
+    ) -> Result<DataSet, Box<dyn Error>> {
         // Get column index
         let left_index = self.key_index(key)?;
         let right_index = right.key_index(key)?;
@@ -105,60 +293,444 @@ impl Aggregate for DataSet {
         self.sort_by_index(left_index)?;
         right.sort_by_index(right_index)?;
 
+        let empty_right = vec![""; right.headers.len()];
+        let empty_left = vec![""; self.headers.len()];
+
         let mut left_cursor = 0;
         let mut right_cursor = 0;
 
         while left_cursor < self.records.len() && right_cursor < right.records.len() {
-            // If two fields match, merge fields into a single record
-            // and add to records vector
-            // If they don't match and the left value is less then right value advance the left cursor
-            // else advance the right cursor
-            if self.records[left_cursor][left_index] == right.records[right_cursor][right_index] {
-                let record = StringRecord::from(
+            let left_key = &self.records[left_cursor][left_index];
+            let right_key = &right.records[right_cursor][right_index];
+
+            if left_key == right_key {
+                // Buffer the full run of equal keys on each side so every
+                // left x right pair within the run gets emitted
+                let mut left_end = left_cursor + 1;
+                while left_end < self.records.len()
+                    && &self.records[left_end][left_index] == left_key
+                {
+                    left_end += 1;
+                }
+
+                let mut right_end = right_cursor + 1;
+                while right_end < right.records.len()
+                    && &right.records[right_end][right_index] == right_key
+                {
+                    right_end += 1;
+                }
+
+                for l in left_cursor..left_end {
+                    for r in right_cursor..right_end {
+                        records.push(StringRecord::from(
+                            self.records[l]
+                                .iter()
+                                .chain(right.records[r].iter())
+                                .collect::<Vec<&str>>(),
+                        ));
+                    }
+                }
+
+                left_cursor = left_end;
+                right_cursor = right_end;
+            } else if left_key < right_key {
+                if kind == JoinKind::Left || kind == JoinKind::Full {
+                    records.push(StringRecord::from(
+                        self.records[left_cursor]
+                            .iter()
+                            .chain(empty_right.iter().copied())
+                            .collect::<Vec<&str>>(),
+                    ));
+                }
+
+                left_cursor += 1;
+            } else {
+                if kind == JoinKind::Right || kind == JoinKind::Full {
+                    records.push(StringRecord::from(
+                        empty_left
+                            .iter()
+                            .copied()
+                            .chain(right.records[right_cursor].iter())
+                            .collect::<Vec<&str>>(),
+                    ));
+                }
+
+                right_cursor += 1;
+            }
+        }
+
+        // Drain any remaining rows left unmatched once one side runs out
+        if kind == JoinKind::Left || kind == JoinKind::Full {
+            while left_cursor < self.records.len() {
+                records.push(StringRecord::from(
                     self.records[left_cursor]
                         .iter()
+                        .chain(empty_right.iter().copied())
+                        .collect::<Vec<&str>>(),
+                ));
+
+                left_cursor += 1;
+            }
+        }
+
+        if kind == JoinKind::Right || kind == JoinKind::Full {
+            while right_cursor < right.records.len() {
+                records.push(StringRecord::from(
+                    empty_left
+                        .iter()
+                        .copied()
                         .chain(right.records[right_cursor].iter())
                         .collect::<Vec<&str>>(),
-                );
+                ));
 
-                records.push(record);
+                right_cursor += 1;
+            }
+        }
 
-                // Since data sets are sorted
-                // Advance cursor through right data set to
-                // see if there are matches
-                let mut k = right_cursor + 1;
-                while k < right.records.len()
-                    && self.records[left_cursor][left_index] == right.records[k][right_index]
-                {
-                    let record = StringRecord::from(
-                        self.records[left_cursor]
+        Ok(DataSet::new(headers, records))
+    }
+}
+
+impl Aggregate for DataSet {
+    /// Performs an inner join on two data sets, where `self` is the left table.
+    ///
+    /// # Arguments
+    ///
+    /// * `right` -> The right data set for the join
+    /// * `key` -> The column name to join on
+    fn inner_join(&mut self, right: &mut Self, key: &str) -> Result<DataSet, Box<dyn Error>> {
+        self.merge_join(right, key, JoinKind::Inner)
+    }
+
+    /// Performs a left outer join, keeping every row from `self` and padding
+    /// unmatched right fields with empty cells.
+    fn left_join(&mut self, right: &mut Self, key: &str) -> Result<DataSet, Box<dyn Error>> {
+        self.merge_join(right, key, JoinKind::Left)
+    }
+
+    /// Performs a right outer join, keeping every row from `right` and padding
+    /// unmatched left fields with empty cells.
+    fn right_join(&mut self, right: &mut Self, key: &str) -> Result<DataSet, Box<dyn Error>> {
+        self.merge_join(right, key, JoinKind::Right)
+    }
+
+    /// Performs a full outer join, keeping every row from both data sets and
+    /// padding whichever side has no match.
+    fn full_outer_join(&mut self, right: &mut Self, key: &str) -> Result<DataSet, Box<dyn Error>> {
+        self.merge_join(right, key, JoinKind::Full)
+    }
+
+    /// Performs a cross join, emitting every left x right combination. Neither
+    /// data set needs to be sorted, since the key is not considered.
+    ///
+    /// # Arguments
+    ///
+    /// * `right` -> The right data set for the join
+    fn cross_join(&self, right: &Self) -> Result<DataSet, Box<dyn Error>> {
+        let headers = StringRecord::from(
+            self.headers
+                .iter()
+                .chain(right.headers.iter())
+                .collect::<Vec<&str>>(),
+        );
+
+        let mut records = vec![];
+
+        for left_record in &self.records {
+            for right_record in &right.records {
+                records.push(StringRecord::from(
+                    left_record
+                        .iter()
+                        .chain(right_record.iter())
+                        .collect::<Vec<&str>>(),
+                ));
+            }
+        }
+
+        Ok(DataSet::new(headers, records))
+    }
+
+    /// Performs an inner join using a hash map over `right` instead of a
+    /// sort-merge, so neither data set is mutated or reordered and `self`'s
+    /// rows are emitted in their original order. Runs in O(n + m) instead of
+    /// the O(n log n) of the sort-merge join, and handles duplicate keys on
+    /// both sides.
+    ///
+    /// # Arguments
+    ///
+    /// * `right` -> The right data set for the join
+    /// * `key` -> The column name to join on
+    fn hash_join(&self, right: &Self, key: &str) -> Result<DataSet, Box<dyn Error>> {
+        let left_index = self.key_index(key)?;
+        let right_index = right.key_index(key)?;
+
+        let headers = StringRecord::from(
+            self.headers
+                .iter()
+                .chain(right.headers.iter())
+                .collect::<Vec<&str>>(),
+        );
+
+        let mut records = vec![];
+
+        if self.records.is_empty() || right.records.is_empty() {
+            return Ok(DataSet::new(headers, records));
+        }
+
+        let mut right_by_key: HashMap<&str, Vec<usize>> = HashMap::new();
+
+        for (i, record) in right.records.iter().enumerate() {
+            right_by_key
+                .entry(&record[right_index])
+                .or_default()
+                .push(i);
+        }
+
+        for left_record in &self.records {
+            if let Some(indices) = right_by_key.get(&left_record[left_index]) {
+                for &i in indices {
+                    records.push(StringRecord::from(
+                        left_record
                             .iter()
-                            .chain(right.records[k].iter())
+                            .chain(right.records[i].iter())
                             .collect::<Vec<&str>>(),
-                    );
+                    ));
+                }
+            }
+        }
+
+        Ok(DataSet::new(headers, records))
+    }
+
+    fn join(
+        &mut self,
+        right: &mut Self,
+        key: &str,
+        kind: JoinKind,
+    ) -> Result<DataSet, Box<dyn Error>> {
+        match kind {
+            JoinKind::Inner => self.inner_join(right, key),
+            JoinKind::Left => self.left_join(right, key),
+            JoinKind::Right => self.right_join(right, key),
+            JoinKind::Full => self.full_outer_join(right, key),
+            JoinKind::Cross => self.cross_join(right),
+            JoinKind::Hash => self.hash_join(right, key),
+        }
+    }
+}
 
-                    records.push(record);
+/// The aggregation function applied to each group in `Analyze::group_by`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AggFn {
+    /// Number of rows in the group, regardless of whether the value column parses
+    Count,
 
-                    k += 1;
+    /// Sum of the numeric values in the group
+    Sum,
+
+    /// Average of the numeric values in the group
+    Avg,
+
+    /// Smallest numeric value in the group
+    Min,
+
+    /// Largest numeric value in the group
+    Max,
+}
+
+/// This trait defines analysis/aggregation methods that summarize a data set
+trait Analyze {
+    fn group_by(
+        &mut self,
+        group_key: &str,
+        value_col: &str,
+        agg: AggFn,
+    ) -> Result<DataSet, Box<dyn Error>>;
+}
+
+impl Analyze for DataSet {
+    /// Groups records by `group_key` and reduces `value_col` within each
+    /// group using `agg`, producing a two-column `DataSet`.
+    ///
+    /// Cells in `value_col` that fail to parse as `f64` are skipped rather
+    /// than causing an error, the same tolerant behavior as
+    /// `csv::invalid_option`. `AggFn::Count` counts every row in the group
+    /// regardless of whether `value_col` parses. A group whose `value_col`
+    /// cells are all invalid yields an empty cell for `Sum`/`Avg`/`Min`/`Max`,
+    /// while `Count` still reports the group's row count.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_key` -> The column name to group by
+    /// * `value_col` -> The column name to aggregate within each group
+    /// * `agg` -> Which aggregation function to apply
+    fn group_by(
+        &mut self,
+        group_key: &str,
+        value_col: &str,
+        agg: AggFn,
+    ) -> Result<DataSet, Box<dyn Error>> {
+        let group_index = self.key_index(group_key)?;
+        let value_index = self.key_index(value_col)?;
+
+        self.sort_by_index(group_index)?;
+
+        let agg_name = match agg {
+            AggFn::Count => "count",
+            AggFn::Sum => "sum",
+            AggFn::Avg => "avg",
+            AggFn::Min => "min",
+            AggFn::Max => "max",
+        };
+
+        let headers = StringRecord::from(vec![
+            group_key.to_string(),
+            format!("{}_{}", agg_name, value_col),
+        ]);
+
+        let mut records = vec![];
+
+        let mut i = 0;
+
+        while i < self.records.len() {
+            let group_value = self.records[i][group_index].to_string();
+
+            let mut j = i;
+            let mut count = 0usize;
+            let mut values = vec![];
+
+            while j < self.records.len() && self.records[j][group_index] == group_value {
+                count += 1;
+
+                if let Ok(value) = self.records[j][value_index].parse::<f64>() {
+                    values.push(value);
                 }
 
-                left_cursor += 1;
-            } else if self.records[left_cursor][left_index]
-                < right.records[right_cursor][right_index]
-            {
-                left_cursor += 1;
-            } else {
-                right_cursor += 1;
+                j += 1;
             }
+
+            let result = match agg {
+                AggFn::Count => count.to_string(),
+                AggFn::Sum => {
+                    if values.is_empty() {
+                        String::new()
+                    } else {
+                        values.iter().sum::<f64>().to_string()
+                    }
+                }
+                AggFn::Avg => {
+                    if values.is_empty() {
+                        String::new()
+                    } else {
+                        (values.iter().sum::<f64>() / values.len() as f64).to_string()
+                    }
+                }
+                AggFn::Min => values
+                    .iter()
+                    .copied()
+                    .fold(None, |acc: Option<f64>, v| {
+                        Some(acc.map_or(v, |a| a.min(v)))
+                    })
+                    .map(|v| v.to_string())
+                    .unwrap_or_default(),
+                AggFn::Max => values
+                    .iter()
+                    .copied()
+                    .fold(None, |acc: Option<f64>, v| {
+                        Some(acc.map_or(v, |a| a.max(v)))
+                    })
+                    .map(|v| v.to_string())
+                    .unwrap_or_default(),
+            };
+
+            records.push(StringRecord::from(vec![group_value, result]));
+
+            i = j;
         }
 
         Ok(DataSet::new(headers, records))
     }
 }
 
+/// Conversions between a `DataSet` and `ndarray::Array2<f64>`, gated behind
+/// the `ndarray` feature since most examples have no need for it.
+#[cfg(feature = "ndarray")]
+impl DataSet {
+    /// Converts the given numeric columns into a 2D `Array2<f64>` in
+    /// row-major order.
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` -> The column names to export, in output order
+    ///
+    /// # Errors
+    ///
+    /// An error occurs if a column does not exist, or if any cell in a
+    /// requested column cannot be parsed as `f64`.
+    fn to_array2(&self, columns: &[&str]) -> Result<ndarray::Array2<f64>, Box<dyn Error>> {
+        let indices = columns
+            .iter()
+            .map(|column| self.key_index(column))
+            .collect::<Result<Vec<usize>, Box<dyn Error>>>()?;
+
+        let mut data = Vec::with_capacity(self.records.len() * columns.len());
+
+        for (row, record) in self.records.iter().enumerate() {
+            for (col, &index) in indices.iter().enumerate() {
+                let value = record[index].parse::<f64>().map_err(|_| {
+                    Box::new(DataSetError::ParseField {
+                        // Falls back to this record's position within `self`
+                        // (1-based) when it carries no file position of its
+                        // own, e.g. a `DataSet` produced by a join or group_by.
+                        row: record.position().map_or(row as u64 + 1, |p| p.line()),
+                        column: columns[col].to_string(),
+                        value: record[index].to_string(),
+                        expected: "f64",
+                    }) as Box<dyn Error>
+                })?;
+
+                data.push(value);
+            }
+        }
+
+        let len = data.len();
+
+        ndarray::Array2::from_shape_vec((self.records.len(), columns.len()), data).map_err(|_| {
+            Box::new(DataSetError::IndexOutOfBounds {
+                index: len,
+                len: self.records.len() * columns.len(),
+            }) as Box<dyn Error>
+        })
+    }
+
+    /// Builds a `DataSet` with the given headers from a 2D `Array2<f64>`.
+    ///
+    /// # Errors
+    ///
+    /// An error occurs if `headers.len()` does not match `array.ncols()`.
+    fn from_array2(headers: &[&str], array: &ndarray::Array2<f64>) -> Result<DataSet, Box<dyn Error>> {
+        if array.ncols() != headers.len() {
+            return Err(Box::new(DataSetError::ShapeMismatch {
+                headers: headers.len(),
+                columns: array.ncols(),
+            }));
+        }
+
+        let records = array
+            .rows()
+            .into_iter()
+            .map(|row| {
+                StringRecord::from(row.iter().map(|v| v.to_string()).collect::<Vec<String>>())
+            })
+            .collect();
+
+        Ok(DataSet::new(StringRecord::from(headers.to_vec()), records))
+    }
+}
+
 /// Reads csv data from a file and returns a DataSet
-fn read_from_file(path: &str) -> Result<DataSet, Box<dyn Error>> {
-    let mut reader = Reader::from_path(path)?;
+fn read_from_file(path: &str, options: &DataSetOptions) -> Result<DataSet, Box<dyn Error>> {
+    let mut reader = options.reader_builder().from_path(path)?;
 
     let headers = reader.headers()?.clone();
 
@@ -170,8 +742,12 @@ fn read_from_file(path: &str) -> Result<DataSet, Box<dyn Error>> {
 }
 
 /// Converts given DataSet to CSV and writes to file
-fn write_to_file(data: DataSet, path: &str) -> Result<(), Box<dyn Error>> {
-    let mut writer = Writer::from_path(path)?;
+fn write_to_file(
+    data: DataSet,
+    path: &str,
+    options: &DataSetOptions,
+) -> Result<(), Box<dyn Error>> {
+    let mut writer = options.writer_builder().from_path(path)?;
 
     writer.write_record(data.headers.iter())?;
 
@@ -183,8 +759,15 @@ fn write_to_file(data: DataSet, path: &str) -> Result<(), Box<dyn Error>> {
 }
 
 fn main() {
+    let options = DataSetOptions::default()
+        .delimiter(b',')
+        .has_headers(true)
+        .flexible(false)
+        .trim(csv::Trim::None)
+        .terminator(csv::Terminator::Any(b'\n'));
+
     // Read customers
-    let mut customers = match read_from_file("./data/Customers.csv") {
+    let mut customers = match read_from_file("./data/Customers.csv", &options) {
         Ok(data) => data,
         Err(e) => {
             eprintln!("{}", e);
@@ -193,7 +776,7 @@ fn main() {
     };
 
     // Read orders
-    let mut orders = match read_from_file("./data/Orders.csv") {
+    let mut orders = match read_from_file("./data/Orders.csv", &options) {
         Ok(data) => data,
         Err(e) => {
             eprintln!("{}", e);
@@ -211,10 +794,138 @@ fn main() {
     };
 
     // Write results to file
-    if let Err(e) = write_to_file(result, "./data/JoinedRecords.csv") {
+    if let Err(e) = write_to_file(result, "./data/JoinedRecords.csv", &options) {
         eprintln!("{}", e);
         process::exit(1);
     } else {
         println!("Inner Join Complete");
     }
+
+    // Report how each join kind changes the matched row count, including the
+    // hash-join fast path, dispatched through `Aggregate::join`
+    for kind in [
+        JoinKind::Left,
+        JoinKind::Right,
+        JoinKind::Full,
+        JoinKind::Cross,
+        JoinKind::Hash,
+    ] {
+        match customers.clone().join(&mut orders.clone(), "customer_guid", kind) {
+            Ok(data) => println!("{:?} join produced {} rows", kind, data.records.len()),
+            Err(e) => eprintln!("{}", e),
+        }
+    }
+
+    // Summarize spend per customer with each aggregation function
+    for agg in [AggFn::Count, AggFn::Sum, AggFn::Avg, AggFn::Min, AggFn::Max] {
+        match orders.clone().group_by("customer_guid", "total", agg) {
+            Ok(totals) => println!("{:?} grouped into {} rows", agg, totals.records.len()),
+            Err(e) => eprintln!("{}", e),
+        }
+    }
+
+    match orders.clone().group_by("customer_guid", "total", AggFn::Sum) {
+        Ok(totals) => {
+            if let Err(e) = write_to_file(totals, "./data/CustomerTotals.csv", &options) {
+                eprintln!("{}", e);
+            } else {
+                println!("Customer Totals Complete");
+            }
+        }
+        Err(e) => eprintln!("{}", e),
+    }
+
+    // Export the per-customer totals to an ndarray for downstream numeric
+    // analysis, then rebuild a `DataSet` from the array, when the `ndarray`
+    // feature is enabled
+    #[cfg(feature = "ndarray")]
+    {
+        match orders.group_by("customer_guid", "total", AggFn::Sum) {
+            Ok(totals) => match totals.to_array2(&["sum_total"]) {
+                Ok(array) => {
+                    println!("Exported customer totals to a {:?} array", array.dim());
+
+                    match DataSet::from_array2(&["sum_total"], &array) {
+                        Ok(rebuilt) => {
+                            println!("Rebuilt {} rows from the array", rebuilt.records.len())
+                        }
+                        Err(e) => eprintln!("{}", e),
+                    }
+                }
+                Err(e) => eprintln!("{}", e),
+            },
+            Err(e) => eprintln!("{}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a `DataSet` from plain string slices, for tests that don't
+    /// need to go through `read_from_file`.
+    fn data_set(headers: &[&str], rows: &[&[&str]]) -> DataSet {
+        DataSet::new(
+            StringRecord::from(headers.to_vec()),
+            rows.iter().map(|row| StringRecord::from(row.to_vec())).collect(),
+        )
+    }
+
+    #[test]
+    fn full_outer_join_pads_unmatched_rows_to_a_rectangular_shape() {
+        let mut left = data_set(
+            &["id", "name"],
+            &[&["1", "alice"], &["2", "bob"], &["3", "carol"]],
+        );
+        let mut right = data_set(&["id", "total"], &[&["2", "10"], &["4", "20"]]);
+
+        let joined = left.full_outer_join(&mut right, "id").unwrap();
+
+        assert_eq!(joined.headers.len(), 4);
+        assert_eq!(joined.records.len(), 4);
+
+        for record in &joined.records {
+            assert_eq!(record.len(), joined.headers.len());
+        }
+    }
+
+    #[test]
+    fn group_by_count_counts_every_row_while_sum_skips_unparseable_cells() {
+        let mut orders = data_set(
+            &["customer_guid", "total"],
+            &[
+                &["a", "10"],
+                &["a", "not-a-number"],
+                &["a", "5"],
+                &["b", "garbage"],
+            ],
+        );
+
+        let counts = orders
+            .clone()
+            .group_by("customer_guid", "total", AggFn::Count)
+            .unwrap();
+        let sums = orders.group_by("customer_guid", "total", AggFn::Sum).unwrap();
+
+        assert_eq!(&counts.records[0][1], "3");
+        assert_eq!(&counts.records[1][1], "1");
+
+        assert_eq!(&sums.records[0][1], "15");
+        assert_eq!(&sums.records[1][1], "");
+    }
+
+    #[test]
+    fn hash_join_preserves_the_left_side_row_order() {
+        let left = data_set(
+            &["id", "name"],
+            &[&["3", "carol"], &["1", "alice"], &["2", "bob"]],
+        );
+        let right = data_set(&["id", "total"], &[&["1", "10"], &["2", "20"], &["3", "30"]]);
+
+        let joined = left.hash_join(&right, "id").unwrap();
+
+        let names: Vec<&str> = joined.records.iter().map(|record| &record[1]).collect();
+        assert_eq!(names, vec!["carol", "alice", "bob"]);
+    }
 }
@@ -1,7 +1,5 @@
 use std::error::Error;
 
-use csv;
-
 /// Reads data from a file into a reader and prints all records.
 ///
 /// # Error
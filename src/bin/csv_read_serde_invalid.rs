@@ -1,8 +1,8 @@
 use std::error::Error;
 
-use csv;
 use serde::Deserialize;
 
+#[allow(dead_code)]
 #[derive(Debug, Deserialize)]
 struct Order {
     order_guid: String,
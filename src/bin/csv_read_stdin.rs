@@ -1,8 +1,6 @@
 use std::error::Error;
 use std::io;
 
-use csv;
-
 /// Reads data from `stdin` into a reader and prints all records.
 ///
 /// # Error
@@ -1,7 +1,5 @@
 use std::error::Error;
 
-use csv;
-
 /// Inserts data into writer and writes to a file
 ///
 /// # Error
@@ -12,21 +10,21 @@ fn write_to_file(path: &str) -> Result<(), Box<dyn Error>> {
     let mut writer = csv::Writer::from_path(path)?;
 
     // Write records one at a time including the header record.
-    writer.write_record(&[
+    writer.write_record([
         "customer_guid",
         "first_name",
         "last_name",
         "email",
         "address",
     ])?;
-    writer.write_record(&[
+    writer.write_record([
         "6e49f2fc-00fd-4502-aed7-812da4aacbb8",
         "Ailey",
         "Benstead",
         "abenstead0@state.gov",
         "554 Mcguire Center",
     ])?;
-    writer.write_record(&[
+    writer.write_record([
         "24349324-7e89-412e-b4bd-2a3c6d8e6d96",
         "Ninnette",
         "Wasmuth",
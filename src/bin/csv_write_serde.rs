@@ -1,7 +1,6 @@
 use std::error::Error;
 use std::io;
 
-use csv;
 use serde::Serialize;
 
 #[derive(Debug, Serialize)]